@@ -4,22 +4,20 @@
 //! and format bytes count back to string.
 //!
 ////////////////////////////////////////////////////////////////////////////////
-extern crate regex;
-
-use regex::Regex;
-
-pub const B: usize = 1;
-pub const KB: usize = 1_000;
-pub const MB: usize = 1_000_000;
-pub const GB: usize = 1_000_000_000;
-pub const TB: usize = 1_000_000_000_000;
-pub const PB: usize = 1_000_000_000_000_000;
-
-pub const KIB: usize = 1_024;
-pub const MIB: usize = 1_048_576;
-pub const GIB: usize = 1_073_741_824;
-pub const TIB: usize = 1_099_511_627_776;
-pub const PIB: usize = 1_125_899_906_842_624;
+pub const B: u64 = 1;
+pub const KB: u64 = 1_000;
+pub const MB: u64 = 1_000_000;
+pub const GB: u64 = 1_000_000_000;
+pub const TB: u64 = 1_000_000_000_000;
+pub const PB: u64 = 1_000_000_000_000_000;
+pub const EB: u64 = 1_000_000_000_000_000_000;
+
+pub const KIB: u64 = 1_024;
+pub const MIB: u64 = 1_048_576;
+pub const GIB: u64 = 1_073_741_824;
+pub const TIB: u64 = 1_099_511_627_776;
+pub const PIB: u64 = 1_125_899_906_842_624;
+pub const EIB: u64 = 1_152_921_504_606_846_976;
 
 #[derive(Debug,PartialEq)]
 pub enum Unit {
@@ -29,45 +27,97 @@ pub enum Unit {
     GB,
     TB,
     PB,
+    EB,
     KIB,
     MIB,
     GIB,
     TIB,
     PIB,
+    EIB,
 }
 
-fn parse_size_unit<S: Into<String>>(s: S) -> Result<(f64, Unit), &'static str> {
-    let str = s.into();
-    let re = Regex::new(r"^(?i)(\d+(\.\d+)?) *((k|m|g|t|p|ki|mi|gi|ti|pi)?b)?$").unwrap();
-    let captures = re.captures(&str);
-    
-    match captures {
-        Some(res) => {
-            let size = res[1].to_owned();
-            let unit: String = match res.get(3) {
-                Some(val) => val.as_str().to_owned().to_uppercase(),
-                None => "B".to_owned(),
-            };
-            
-            Ok((size.parse::<f64>().unwrap(), match &*unit {
-                "B" => Unit::B,
-                "KB" => Unit::KB,
-                "MB" => Unit::MB,
-                "GB" => Unit::GB,
-                "TB" => Unit::TB,
-                "PB" => Unit::PB,
-                "KIB" => Unit::KIB,
-                "MIB" => Unit::MIB,
-                "GIB" => Unit::GIB,
-                "TIB" => Unit::TIB,
-                "PIB" => Unit::PIB,
-                _ => Unit::B,
-            }))
+/// The error returned when a byte size string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input string was empty.
+    Empty,
+    /// The numeric part of the input couldn't be parsed as a number.
+    InvalidNumber(String),
+    /// The unit suffix wasn't recognized.
+    UnknownUnit(String),
+    /// The input described a negative byte size.
+    Negative,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Parse Error. Input was empty."),
+            ParseError::InvalidNumber(s) => write!(f, "Parse Error. Couldn't parse number: {}", s),
+            ParseError::UnknownUnit(s) => write!(f, "Parse Error. Unknown byte unit: {}", s),
+            ParseError::Negative => write!(f, "Parse Error. Negative byte size is not supported."),
         }
-        None => Err("Parse Error. Invalid byte format."),
     }
 }
 
+impl std::error::Error for ParseError {}
+
+fn parse_size_unit<S: Into<String>>(s: S) -> Result<(f64, Unit), ParseError> {
+    let str = s.into();
+    let trimmed = str.trim();
+
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    if trimmed.starts_with('-') {
+        return Err(ParseError::Negative);
+    }
+
+    // Fast path: a plain integer byte count with no unit.
+    if let Ok(value) = trimmed.parse::<u64>() {
+        return Ok((value as f64, Unit::B));
+    }
+
+    let numeric: String = trimmed
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+
+    if numeric.is_empty() {
+        return Err(ParseError::InvalidNumber(trimmed.to_owned()));
+    }
+
+    let value: f64 = numeric
+        .parse()
+        .map_err(|_| ParseError::InvalidNumber(numeric.clone()))?;
+
+    let rest: String = trimmed[numeric.len()..]
+        .chars()
+        .skip_while(|c| c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+
+    let unit = match &*rest {
+        "" | "b" => Unit::B,
+        "kb" => Unit::KB,
+        "mb" => Unit::MB,
+        "gb" => Unit::GB,
+        "tb" => Unit::TB,
+        "pb" => Unit::PB,
+        "eb" => Unit::EB,
+        "kib" => Unit::KIB,
+        "mib" => Unit::MIB,
+        "gib" => Unit::GIB,
+        "tib" => Unit::TIB,
+        "pib" => Unit::PIB,
+        "eib" => Unit::EIB,
+        _ => return Err(ParseError::UnknownUnit(rest)),
+    };
+
+    Ok((value, unit))
+}
+
 /// Parse given string to bytes size
 ///
 /// # Examples  
@@ -86,14 +136,14 @@ fn parse_size_unit<S: Into<String>>(s: S) -> Result<(f64, Unit), &'static str> {
 /// assert_eq!(byteunit::parse("1.23 TiB").unwrap(), 1_352_399_302_164);
 /// assert_eq!(byteunit::parse("1.23 PiB").unwrap(), 1_384_856_885_416_427);
 /// ```
-pub fn parse<S: Into<String>>(str: S) -> Result<usize, &'static str> {
+pub fn parse<S: Into<String>>(str: S) -> Result<u64, ParseError> {
     let parsed = parse_size_unit(str);
 
     match parsed {
         Ok(r) => {
             let value = r.0;
             let unit = r.1;
-            
+
             let bytes = match unit {
                 Unit::B => value * B as f64,
                 Unit::KB => value * KB as f64,
@@ -101,14 +151,16 @@ pub fn parse<S: Into<String>>(str: S) -> Result<usize, &'static str> {
                 Unit::GB => value * GB as f64,
                 Unit::TB => value * TB as f64,
                 Unit::PB => value * PB as f64,
+                Unit::EB => value * EB as f64,
                 Unit::KIB => value * KIB as f64,
                 Unit::MIB => value * MIB as f64,
                 Unit::GIB => value * GIB as f64,
                 Unit::TIB => value * TIB as f64,
                 Unit::PIB => value * PIB as f64,
+                Unit::EIB => value * EIB as f64,
             };
 
-            Ok(bytes as usize)
+            Ok(bytes as u64)
         },
         Err(msg) => Err(msg),
     }
@@ -125,7 +177,7 @@ pub fn parse<S: Into<String>>(str: S) -> Result<usize, &'static str> {
 /// assert_eq!(kb, 0.123);
 /// assert_eq!(mb, 0.000123);
 /// ```
-pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, &'static str> {
+pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, ParseError> {
     match parse(str) {
         Ok(bytes) => {
             let result = match result_unit {
@@ -135,11 +187,13 @@ pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, &'sta
                 Unit::GB => bytes as f64 / GB as f64,
                 Unit::TB => bytes as f64 / TB as f64,
                 Unit::PB => bytes as f64 / PB as f64,
+                Unit::EB => bytes as f64 / EB as f64,
                 Unit::KIB => bytes as f64 / KIB as f64,
                 Unit::MIB => bytes as f64 / MIB as f64,
                 Unit::GIB => bytes as f64 / GIB as f64,
                 Unit::TIB => bytes as f64 / TIB as f64,
                 Unit::PIB => bytes as f64 / PIB as f64,
+                Unit::EIB => bytes as f64 / EIB as f64,
             };
 
             Ok(result)
@@ -160,7 +214,7 @@ pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, &'sta
 /// assert_eq!(byteunit::format(1_230_000_000_000), "1.23 TB");
 /// assert_eq!(byteunit::format(1_230_000_000_000_000), "1.23 PB");
 /// ```
-pub fn format(bytes: usize) -> String {
+pub fn format(bytes: u64) -> String {
     if bytes < KB {
         return format_to(bytes, Unit::B);
     }
@@ -181,7 +235,11 @@ pub fn format(bytes: usize) -> String {
         return format_to(bytes, Unit::TB);
     }
 
-    format_to(bytes, Unit::PB)
+    if bytes < EB {
+        return format_to(bytes, Unit::PB);
+    }
+
+    format_to(bytes, Unit::EB)
 }
 
 /// Format bytes to specific unit byte size string
@@ -194,7 +252,7 @@ pub fn format(bytes: usize) -> String {
 /// assert_eq!(byteunit::format_to(500, byteunit::Unit::KB), "0.5 KB");
 /// assert_eq!(byteunit::format_to(512, byteunit::Unit::KIB), "0.5 KiB");
 /// ```
-pub fn format_to(bytes: usize, unit: Unit) -> String {
+pub fn format_to(bytes: u64, unit: Unit) -> String {
     let result = match unit {
         Unit::B => bytes as f64,
         Unit::KB => bytes as f64 / KB as f64,
@@ -202,11 +260,13 @@ pub fn format_to(bytes: usize, unit: Unit) -> String {
         Unit::GB => bytes as f64 / GB as f64,
         Unit::TB => bytes as f64 / TB as f64,
         Unit::PB => bytes as f64 / PB as f64,
+        Unit::EB => bytes as f64 / EB as f64,
         Unit::KIB => bytes as f64 / KIB as f64,
         Unit::MIB => bytes as f64 / MIB as f64,
         Unit::GIB => bytes as f64 / GIB as f64,
         Unit::TIB => bytes as f64 / TIB as f64,
         Unit::PIB => bytes as f64 / PIB as f64,
+        Unit::EIB => bytes as f64 / EIB as f64,
     };
 
     let mut str = format!("{:.2}", result)
@@ -221,20 +281,233 @@ pub fn format_to(bytes: usize, unit: Unit) -> String {
         Unit::GB => str.push_str(" GB"),
         Unit::TB => str.push_str(" TB"),
         Unit::PB => str.push_str(" PB"),
+        Unit::EB => str.push_str(" EB"),
         Unit::KIB => str.push_str(" KiB"),
         Unit::MIB => str.push_str(" MiB"),
         Unit::GIB => str.push_str(" GiB"),
         Unit::TIB => str.push_str(" TiB"),
         Unit::PIB => str.push_str(" PiB"),
+        Unit::EIB => str.push_str(" EiB"),
     }
 
     str
 }
 
+/// Format bytes to byte size string using binary (IEC) units.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(byteunit::format_binary(512), "512 B");
+/// assert_eq!(byteunit::format_binary(1_536), "1.5 KiB");
+/// assert_eq!(byteunit::format_binary(1_610_612_736), "1.5 GiB");
+/// ```
+pub fn format_binary(bytes: u64) -> String {
+    if bytes < KIB {
+        return format_to(bytes, Unit::B);
+    }
+
+    if bytes < MIB {
+        return format_to(bytes, Unit::KIB);
+    }
+
+    if bytes < GIB {
+        return format_to(bytes, Unit::MIB);
+    }
+
+    if bytes < TIB {
+        return format_to(bytes, Unit::GIB);
+    }
+
+    if bytes < PIB {
+        return format_to(bytes, Unit::TIB);
+    }
+
+    if bytes < EIB {
+        return format_to(bytes, Unit::PIB);
+    }
+
+    format_to(bytes, Unit::EIB)
+}
+
+/// Format bytes as binary (IEC), decimal (SI), or both side by side.
+///
+/// Unlike [`format`] and [`format_to`], a scaled number always keeps
+/// its two decimal places (no trailing-zero trimming), matching the output
+/// style of common disk utilities. Values below 1 KiB (binary and combined
+/// forms) or 1 KB (decimal form), and any value when neither flag is set,
+/// are written as a plain integer `"N bytes"`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(byteunit::format_pretty(1_610_612_736, true, true), "1.50 GiB (1.61 GB)");
+/// assert_eq!(byteunit::format_pretty(1_610_612_736, true, false), "1.50 GiB");
+/// assert_eq!(byteunit::format_pretty(1_610_612_736, false, true), "1.61 GB");
+/// assert_eq!(byteunit::format_pretty(512, true, true), "512 bytes");
+/// ```
+pub fn format_pretty(bytes: u64, binary: bool, decimal: bool) -> String {
+    match (binary, decimal) {
+        (true, true) => {
+            if bytes < KIB {
+                format!("{} bytes", bytes)
+            } else {
+                format!("{} ({})", format_binary_fixed(bytes), format_decimal_fixed(bytes))
+            }
+        }
+        (true, false) => format_binary_fixed(bytes),
+        (false, true) => format_decimal_fixed(bytes),
+        (false, false) => format!("{} bytes", bytes),
+    }
+}
+
+fn format_binary_fixed(bytes: u64) -> String {
+    if bytes < KIB {
+        return format!("{} bytes", bytes);
+    }
+
+    let (value, suffix) = if bytes >= EIB {
+        (bytes as f64 / EIB as f64, "EiB")
+    } else if bytes >= PIB {
+        (bytes as f64 / PIB as f64, "PiB")
+    } else if bytes >= TIB {
+        (bytes as f64 / TIB as f64, "TiB")
+    } else if bytes >= GIB {
+        (bytes as f64 / GIB as f64, "GiB")
+    } else if bytes >= MIB {
+        (bytes as f64 / MIB as f64, "MiB")
+    } else {
+        (bytes as f64 / KIB as f64, "KiB")
+    };
+
+    format!("{:.2} {}", value, suffix)
+}
+
+fn format_decimal_fixed(bytes: u64) -> String {
+    if bytes < KB {
+        return format!("{} bytes", bytes);
+    }
+
+    let (value, suffix) = if bytes >= EB {
+        (bytes as f64 / EB as f64, "EB")
+    } else if bytes >= PB {
+        (bytes as f64 / PB as f64, "PB")
+    } else if bytes >= TB {
+        (bytes as f64 / TB as f64, "TB")
+    } else if bytes >= GB {
+        (bytes as f64 / GB as f64, "GB")
+    } else if bytes >= MB {
+        (bytes as f64 / MB as f64, "MB")
+    } else {
+        (bytes as f64 / KB as f64, "KB")
+    };
+
+    format!("{:.2} {}", value, suffix)
+}
+
+/// A byte size wrapped in a newtype so it can be parsed, formatted, and
+/// composed like any other value (e.g. stored directly in a config struct).
+///
+/// `ByteSize` only stores the byte count, not the unit it was parsed from,
+/// so `Display` always renders the decimal SI form via [`format`] regardless
+/// of which unit the input string used. Serde (de)serializes the raw `u64`
+/// byte count directly, not the display string, so it round-trips exactly.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use byteunit::ByteSize;
+///
+/// let size = ByteSize::from_str("1.5 GiB").unwrap();
+/// assert_eq!(size.to_string(), "1.61 GB");
+/// assert_eq!(u64::from(size), 1_610_612_736);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+impl std::str::FromStr for ByteSize {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(ByteSize)
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", format(self.0))
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+impl std::ops::Add for ByteSize {
+    type Output = ByteSize;
+
+    fn add(self, rhs: ByteSize) -> ByteSize {
+        ByteSize(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for ByteSize {
+    type Output = ByteSize;
+
+    fn sub(self, rhs: ByteSize) -> ByteSize {
+        ByteSize(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul<u64> for ByteSize {
+    type Output = ByteSize;
+
+    fn mul(self, rhs: u64) -> ByteSize {
+        ByteSize(self.0 * rhs)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(ByteSize)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("").unwrap_err(), ParseError::Empty);
+        assert_eq!(parse("-10").unwrap_err(), ParseError::Negative);
+        assert_eq!(parse("abc").unwrap_err(), ParseError::InvalidNumber("abc".to_owned()));
+        assert_eq!(parse("123 zz").unwrap_err(), ParseError::UnknownUnit("zz".to_owned()));
+    }
+
     #[test]
     fn test_parse_size_unit() {
         assert_eq!(parse_size_unit("123").unwrap(), (123_f64, Unit::B));
@@ -291,6 +564,13 @@ mod tests {
         assert_eq!(parse_size_unit("12.34 pib").unwrap(), (12.34_f64, Unit::PIB));
         assert_eq!(parse_size_unit("12.34 PB").unwrap(), (12.34_f64, Unit::PB));
         assert_eq!(parse_size_unit("12.34 PiB").unwrap(), (12.34_f64, Unit::PIB));
+
+        assert_eq!(parse_size_unit("12.34eb").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34eib").unwrap(), (12.34_f64, Unit::EIB));
+        assert_eq!(parse_size_unit("12.34EB").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34EiB").unwrap(), (12.34_f64, Unit::EIB));
+        assert_eq!(parse_size_unit("12.34 eb").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34 eib").unwrap(), (12.34_f64, Unit::EIB));
     }
 
     #[test]
@@ -307,6 +587,8 @@ mod tests {
         assert_eq!(parse("1.23GIB").unwrap(), 1_320_702_443);
         assert_eq!(parse("1.23TIB").unwrap(), 1_352_399_302_164);
         assert_eq!(parse("1.23PIB").unwrap(), 1_384_856_885_416_427);
+        assert_eq!(parse("1.23EB").unwrap(), 1_230_000_000_000_000_000);
+        assert_eq!(parse("1.23EIB").unwrap(), 1_418_093_450_666_421_760);
     }
 
     #[test]
@@ -322,6 +604,8 @@ mod tests {
         assert_eq!(format!("{:.2}", parse_to("1.23GIB", Unit::GIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23TIB", Unit::TIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23PIB", Unit::PIB).unwrap()), "1.23");
+        assert_eq!(format!("{:.2}", parse_to("1.23EB", Unit::EB).unwrap()), "1.23");
+        assert_eq!(format!("{:.2}", parse_to("1.23EIB", Unit::EIB).unwrap()), "1.23");
     }
 
     #[test]
@@ -332,6 +616,18 @@ mod tests {
         assert_eq!(format(1_230_000_000), "1.23 GB");
         assert_eq!(format(1_230_000_000_000), "1.23 TB");
         assert_eq!(format(1_230_000_000_000_000), "1.23 PB");
+        assert_eq!(format(1_230_000_000_000_000_000), "1.23 EB");
+    }
+
+    #[test]
+    fn test_format_binary() {
+        assert_eq!(format_binary(512), "512 B");
+        assert_eq!(format_binary(1_536), "1.5 KiB");
+        assert_eq!(format_binary(1_572_864), "1.5 MiB");
+        assert_eq!(format_binary(1_610_612_736), "1.5 GiB");
+        assert_eq!(format_binary(1_649_267_441_664), "1.5 TiB");
+        assert_eq!(format_binary(1_688_849_860_263_936), "1.5 PiB");
+        assert_eq!(format_binary(1_729_382_256_910_270_464), "1.5 EiB");
     }
 
 
@@ -348,6 +644,8 @@ mod tests {
         assert_eq!(format_to(1_337_882_312, Unit::GIB), "1.25 GiB");
         assert_eq!(format_to(1_369_991_488_208, Unit::TIB), "1.25 TiB");
         assert_eq!(format_to(1_402_871_283_925_909, Unit::PIB), "1.25 PiB");
+        assert_eq!(format_to(1_250_000_000_000_000_000, Unit::EB), "1.25 EB");
+        assert_eq!(format_to(1_441_151_880_758_558_720, Unit::EIB), "1.25 EiB");
 
         assert_eq!(format_to(500, Unit::KB), "0.5 KB");
         assert_eq!(format_to(500_000, Unit::MB), "0.5 MB");
@@ -359,5 +657,44 @@ mod tests {
         assert_eq!(format_to(536_870_912, Unit::GIB), "0.5 GiB");
         assert_eq!(format_to(549_755_813_888, Unit::TIB), "0.5 TiB");
         assert_eq!(format_to(562_949_953_421_312, Unit::PIB), "0.5 PiB");
+        assert_eq!(format_to(500_000_000_000_000_000, Unit::EB), "0.5 EB");
+        assert_eq!(format_to(576_460_752_303_423_488, Unit::EIB), "0.5 EiB");
+    }
+
+    #[test]
+    fn test_format_pretty() {
+        assert_eq!(format_pretty(1_610_612_736, true, true), "1.50 GiB (1.61 GB)");
+        assert_eq!(format_pretty(1_610_612_736, true, false), "1.50 GiB");
+        assert_eq!(format_pretty(1_610_612_736, false, true), "1.61 GB");
+        assert_eq!(format_pretty(1_610_612_736, false, false), "1610612736 bytes");
+
+        assert_eq!(format_pretty(512, true, true), "512 bytes");
+        assert_eq!(format_pretty(512, true, false), "512 bytes");
+        assert_eq!(format_pretty(512, false, true), "512 bytes");
+
+        assert_eq!(format_pretty(1_536, true, true), "1.50 KiB (1.54 KB)");
+
+        // Below 1 KiB but at/above 1 KB: the combined and binary forms fall
+        // back to "N bytes", while the decimal form alone already uses KB.
+        assert_eq!(format_pretty(1_010, true, true), "1010 bytes");
+        assert_eq!(format_pretty(1_010, true, false), "1010 bytes");
+        assert_eq!(format_pretty(1_010, false, true), "1.01 KB");
+    }
+
+    #[test]
+    fn test_byte_size() {
+        use std::str::FromStr;
+
+        assert_eq!(ByteSize::from_str("123").unwrap(), ByteSize(123));
+        assert_eq!(ByteSize::from_str("1.5 GiB").unwrap(), ByteSize(1_610_612_736));
+        assert!(ByteSize::from_str("-10").is_err());
+
+        assert_eq!(ByteSize(1_230).to_string(), "1.23 KB");
+        assert_eq!(u64::from(ByteSize(123)), 123);
+        assert_eq!(ByteSize::from(123u64), ByteSize(123));
+
+        assert_eq!(ByteSize(100) + ByteSize(50), ByteSize(150));
+        assert_eq!(ByteSize(100) - ByteSize(50), ByteSize(50));
+        assert_eq!(ByteSize(100) * 3, ByteSize(300));
     }
 }
\ No newline at end of file